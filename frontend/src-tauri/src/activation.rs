@@ -0,0 +1,82 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Something Regia was asked to open, whether via a file-association
+/// launch, a `regia://` deep link, or a second-instance document argument.
+///
+/// Forwarded to the frontend as the `open-target` event so there is a
+/// single typed shape for every activation path to agree on.
+#[derive(Clone, Serialize)]
+#[serde(tag = "kind", content = "target")]
+pub enum ActivationTarget {
+    Document(PathBuf),
+    Link(String),
+    /// Jump to the analysis results for an already-opened document, keyed
+    /// by doc id rather than a filesystem path. Used to route notification
+    /// clicks (see `commands::focus_document`) back to the right document.
+    Analysis(String),
+}
+
+/// Activation targets received before the `"main"` webview has finished
+/// its initial load, held until it's ready to receive `open-target`.
+///
+/// `ready` and `queue` are guarded by a single mutex so a target can't be
+/// pushed onto the queue in the gap between `flush` draining it and
+/// flipping the ready flag — otherwise that target would never be replayed
+/// (`flush` only ever runs once, on the main window's first page load).
+#[derive(Default)]
+pub struct PendingActivations {
+    state: Mutex<PendingState>,
+}
+
+#[derive(Default)]
+struct PendingState {
+    ready: bool,
+    queue: VecDeque<ActivationTarget>,
+}
+
+impl PendingActivations {
+    /// The single entry point for every activation source (file
+    /// association, deep link, second instance, cold-start argv): focuses
+    /// the main window and either emits immediately or queues for replay.
+    pub fn activate(app: &AppHandle, target: ActivationTarget) {
+        let state = app.state::<PendingActivations>();
+
+        let Some(window) = app.get_webview_window("main") else {
+            state.state.lock().unwrap().queue.push_back(target);
+            return;
+        };
+        window.unminimize().ok();
+        window.set_focus().ok();
+
+        let mut guard = state.state.lock().unwrap();
+        if guard.ready {
+            drop(guard);
+            window.emit("open-target", target).ok();
+        } else {
+            guard.queue.push_back(target);
+        }
+    }
+
+    /// Marks the main window ready and flushes anything queued while it was
+    /// still loading.
+    pub fn flush(app: &AppHandle) {
+        let state = app.state::<PendingActivations>();
+        let queued = {
+            let mut guard = state.state.lock().unwrap();
+            guard.ready = true;
+            std::mem::take(&mut guard.queue)
+        };
+
+        let Some(window) = app.get_webview_window("main") else {
+            return;
+        };
+        for target in queued {
+            window.emit("open-target", target).ok();
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use serde::Serialize;
+use serde_json::json;
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::activation::{ActivationTarget, PendingActivations};
+use crate::documents::{DocumentStore, RenderedPage};
+use crate::error::SerializableError;
+use crate::jobs::{JobRegistry, ProgressEvent};
+
+/// Result of extracting plain text from a document.
+#[derive(Serialize)]
+pub struct ExtractedText {
+    pub text: String,
+}
+
+/// Result of running document intelligence over an already-opened document.
+#[derive(Clone, Serialize)]
+pub struct DocumentAnalysis {
+    pub doc_id: String,
+    pub page_count: u32,
+}
+
+/// A single search hit within the document index.
+#[derive(Serialize)]
+pub struct SearchHit {
+    pub doc_id: String,
+    pub page: u32,
+    pub snippet: String,
+}
+
+/// Text-like extensions this command can actually handle today. PDF/DOCX
+/// extraction is the whole point of the file associations chunk0-2 wires
+/// up, but the OCR/parsing engine for those formats doesn't exist yet —
+/// replaced once it lands, the same way `analyze_document`'s paging loop
+/// stands in for the real analysis engine.
+const TEXT_LIKE_EXTENSIONS: &[&str] = &["txt", "md", "csv", "json"];
+
+/// Reads `path` and extracts its plain text. Runs on the async runtime so
+/// OCR/parsing never blocks the WRY event loop.
+#[tauri::command]
+pub async fn extract_text(path: String) -> Result<ExtractedText, SerializableError> {
+    let is_text_like = std::path::Path::new(&path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| TEXT_LIKE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+    if !is_text_like {
+        return Err(SerializableError::Extraction(format!(
+            "\"{path}\": text extraction for this format isn't implemented yet"
+        )));
+    }
+
+    let bytes = tokio::fs::read(&path).await.map_err(|source| SerializableError::Io {
+        path: path.clone(),
+        source: source.to_string(),
+    })?;
+
+    let text = String::from_utf8(bytes)
+        .map_err(|_| SerializableError::Extraction(format!("\"{path}\" is not valid UTF-8 text")))?;
+
+    Ok(ExtractedText { text })
+}
+
+/// Opens `path` and registers it with the `DocumentStore` under a fresh
+/// doc id, so `analyze_document` and the `regia-doc://` protocol handler
+/// have something to serve. Stands in for the real renderer (PDF/DOCX →
+/// page images) — today it stores the raw file bytes as a single "page 1",
+/// replaced once rendering lands.
+#[tauri::command]
+pub async fn open_document(path: String, store: State<'_, DocumentStore>) -> Result<String, SerializableError> {
+    let bytes = tokio::fs::read(&path).await.map_err(|source| SerializableError::Io {
+        path: path.clone(),
+        source: source.to_string(),
+    })?;
+
+    static NEXT_DOC_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+    let doc_id = format!("doc-{}", NEXT_DOC_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
+    store.insert_page(
+        doc_id.clone(),
+        1,
+        RenderedPage { content_type: "application/octet-stream".to_string(), bytes: bytes.into() },
+    );
+
+    Ok(doc_id)
+}
+
+/// Kicks off analysis of an already-opened document as a cancellable
+/// background job, identified by `id` (one analysis runs per document at a
+/// time). Progress is streamed over `on_progress`; a completed run fires an
+/// OS notification and a `job-complete` event on the main window, while a
+/// cancelled run fires only an OS notification. The job has no failure path.
+#[tauri::command]
+pub async fn analyze_document(
+    id: String,
+    on_progress: Channel<ProgressEvent>,
+    app: AppHandle,
+    jobs: State<'_, JobRegistry>,
+    store: State<'_, DocumentStore>,
+) -> Result<String, SerializableError> {
+    let page_count = store
+        .page_count(&id)
+        .ok_or_else(|| SerializableError::DocumentNotFound(id.clone()))?;
+
+    let token = jobs
+        .try_start(id.clone())
+        .ok_or_else(|| SerializableError::JobAlreadyRunning(id.clone()))?;
+    let doc_id = id.clone();
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let mut cancelled = false;
+        for page in 1..=page_count {
+            if token.is_cancelled() {
+                cancelled = true;
+                break;
+            }
+
+            // Stand-in for the real OCR/parsing work this command exists
+            // to front; replaced once the analysis engine lands.
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+            let percent = ((page as f32 / page_count as f32) * 100.0) as u8;
+            on_progress
+                .send(ProgressEvent { stage: "analyzing".into(), page, total_pages: page_count, percent })
+                .ok();
+        }
+
+        app.state::<JobRegistry>().finish(&doc_id);
+
+        if cancelled {
+            notify(&app, "Analysis cancelled", "The analysis job was cancelled.", &doc_id);
+            return;
+        }
+
+        if let Some(window) = app.get_webview_window("main") {
+            window
+                .emit("job-complete", &DocumentAnalysis { doc_id: doc_id.clone(), page_count })
+                .ok();
+        }
+        notify(&app, "Analysis complete", "Tap to view the results.", &doc_id);
+    });
+
+    Ok(id)
+}
+
+/// Cancels a running `analyze_document` job by id.
+#[tauri::command]
+pub async fn cancel_job(job_id: String, jobs: State<'_, JobRegistry>) -> Result<(), SerializableError> {
+    if jobs.cancel(&job_id) {
+        Ok(())
+    } else {
+        Err(SerializableError::JobNotFound(job_id))
+    }
+}
+
+/// Called by the frontend's notification click handler with the doc id it
+/// read back out of the notification's structured `extra` data. Routes
+/// through the same `open-target` activation path as file associations and
+/// deep links, so the main window focuses and jumps to that document's
+/// analysis results.
+#[tauri::command]
+pub async fn focus_document(doc_id: String, app: AppHandle) -> Result<(), SerializableError> {
+    PendingActivations::activate(&app, ActivationTarget::Analysis(doc_id));
+    Ok(())
+}
+
+/// Searches the document index for `query`, returning matching snippets.
+#[tauri::command]
+pub async fn search_index(query: String) -> Result<Vec<SearchHit>, SerializableError> {
+    if query.trim().is_empty() {
+        return Err(SerializableError::Extraction("search query must not be empty".into()));
+    }
+
+    // The index itself is built by the indexing subsystem; this command is
+    // the IPC surface it will be wired into.
+    Ok(Vec::new())
+}
+
+/// Fires a completion/failure notification for a job, attaching the doc id
+/// as structured `extra` data rather than folding it into `body`. The
+/// frontend's notification click handler reads `extra.docId` back out and
+/// calls `focus_document` to jump straight to the relevant document.
+///
+/// NOTE: `.extra(...)` taking one serialized payload is assumed from the
+/// plugin's JS-side `extra` option; this tree has no `Cargo.toml` pinning a
+/// `tauri-plugin-notification` version to compile against, so double-check
+/// this call against the real `NotificationBuilder` API (some Tauri
+/// builders take `extra(key, value)` pairs instead) once the manifest
+/// lands.
+fn notify(app: &AppHandle, title: &str, body: &str, doc_id: &str) {
+    app.notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .extra(json!({ "docId": doc_id }))
+        .show()
+        .ok();
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A single rendered document page, ready to be streamed to the webview.
+#[derive(Clone)]
+pub struct RenderedPage {
+    pub content_type: String,
+    pub bytes: Arc<[u8]>,
+}
+
+/// In-memory store of rendered document pages, keyed by document id and
+/// page number.
+#[derive(Default)]
+pub struct DocumentStore {
+    pages: Mutex<HashMap<(String, u32), RenderedPage>>,
+}
+
+impl DocumentStore {
+    /// Looks up a rendered page for `regia-doc://<docid>/page/<n>`.
+    pub fn page(&self, doc_id: &str, page: u32) -> Option<RenderedPage> {
+        self.pages.lock().unwrap().get(&(doc_id.to_string(), page)).cloned()
+    }
+
+    /// Stores (or replaces) a rendered page.
+    pub fn insert_page(&self, doc_id: impl Into<String>, page: u32, rendered: RenderedPage) {
+        self.pages.lock().unwrap().insert((doc_id.into(), page), rendered);
+    }
+
+    /// Number of pages known for `doc_id`, or `None` if it hasn't been seen.
+    pub fn page_count(&self, doc_id: &str) -> Option<u32> {
+        let count = self
+            .pages
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|(id, _)| id == doc_id)
+            .count();
+        (count > 0).then(|| count as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_look_up_a_page() {
+        let store = DocumentStore::default();
+        assert!(store.page("doc-1", 1).is_none());
+        assert_eq!(store.page_count("doc-1"), None);
+
+        store.insert_page(
+            "doc-1",
+            1,
+            RenderedPage { content_type: "text/plain".to_string(), bytes: Arc::from(*b"hello") },
+        );
+
+        let page = store.page("doc-1", 1).unwrap();
+        assert_eq!(page.content_type, "text/plain");
+        assert_eq!(&*page.bytes, b"hello");
+        assert_eq!(store.page_count("doc-1"), Some(1));
+    }
+
+    #[test]
+    fn insert_page_replaces_existing_page() {
+        let store = DocumentStore::default();
+        store.insert_page("doc-1", 1, RenderedPage { content_type: "a".to_string(), bytes: Arc::from(*b"x") });
+        store.insert_page("doc-1", 1, RenderedPage { content_type: "b".to_string(), bytes: Arc::from(*b"y") });
+
+        let page = store.page("doc-1", 1).unwrap();
+        assert_eq!(page.content_type, "b");
+        assert_eq!(&*page.bytes, b"y");
+    }
+}
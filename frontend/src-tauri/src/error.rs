@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+/// Errors surfaced to the frontend from document-processing commands.
+///
+/// Serializes to a tagged object instead of relying on `Display`, so the
+/// frontend can switch on `kind` rather than pattern-match strings.
+#[derive(Debug, thiserror::Error, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum SerializableError {
+    #[error("document not found: {0}")]
+    DocumentNotFound(String),
+
+    #[error("job not found: {0}")]
+    JobNotFound(String),
+
+    #[error("analysis is already running for document: {0}")]
+    JobAlreadyRunning(String),
+
+    #[error("failed to read \"{path}\": {source}")]
+    Io { path: String, source: String },
+
+    #[error("{0}")]
+    Extraction(String),
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+/// A single stage of progress reported while an analysis job runs.
+#[derive(Clone, Serialize)]
+pub struct ProgressEvent {
+    pub stage: String,
+    pub page: u32,
+    pub total_pages: u32,
+    pub percent: u8,
+}
+
+/// A cheaply-cloneable flag a running job polls to notice it's been asked
+/// to stop.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Tracks the cancellation tokens for in-flight analysis jobs, keyed by job
+/// id, so the frontend can cancel one mid-run via `cancel_job`.
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl JobRegistry {
+    /// Registers a new job and returns the token it should poll, or `None`
+    /// if a job for this id is already running — only one analysis runs
+    /// per document at a time, so a second `analyze_document` call for the
+    /// same id must not silently replace the first job's token.
+    pub fn try_start(&self, job_id: impl Into<String>) -> Option<CancellationToken> {
+        use std::collections::hash_map::Entry;
+
+        let mut jobs = self.jobs.lock().unwrap();
+        match jobs.entry(job_id.into()) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let token = CancellationToken::default();
+                entry.insert(token.clone());
+                Some(token)
+            }
+        }
+    }
+
+    /// Removes a job once it has finished, failed, or been cancelled.
+    pub fn finish(&self, job_id: &str) {
+        self.jobs.lock().unwrap().remove(job_id);
+    }
+
+    /// Requests cancellation of a running job. Returns `false` if no job
+    /// with that id is currently tracked.
+    pub fn cancel(&self, job_id: &str) -> bool {
+        match self.jobs.lock().unwrap().get(job_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
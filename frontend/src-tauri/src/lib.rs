@@ -1,18 +1,220 @@
+// This crate ships as a source tree without `Cargo.toml`/`tauri.conf.json`
+// checked in, so nothing here has actually been built. The modules below
+// assume the following are declared wherever the manifest lives:
+//   - deps: tauri (with the `protocol-asset`-equivalent APIs used by
+//     `protocol.rs`), tauri-plugin-{shell,http,process,os,notification,
+//     deep-link,single-instance}, serde, serde_json, thiserror, url, http,
+//     tokio
+//   - a `background-mode` Cargo feature gating `tray.rs` and its tray-icon
+//     dependency
+//   - `tauri.conf.json`: the `regia` deep-link scheme, `.pdf`/`.docx`/`.txt`
+//     file associations, and IPC capability permissions allowing the
+//     frontend to call the commands registered in `invoke_handler!` and
+//     load `regia-doc://` assets
+// Land those alongside (not faked here) before any of this compiles.
+mod activation;
+mod commands;
+mod documents;
+mod error;
+mod jobs;
+mod protocol;
+#[cfg(feature = "background-mode")]
+mod tray;
+
+use std::path::{Path, PathBuf};
+
+use activation::{ActivationTarget, PendingActivations};
+use documents::DocumentStore;
+use jobs::JobRegistry;
 use tauri::Manager;
 
+/// Pulls the document paths out of a process `argv`, dropping the binary
+/// name and anything that looks like a CLI flag rather than a file.
+fn document_args(argv: &[String]) -> impl Iterator<Item = &str> {
+    argv.iter()
+        .skip(1)
+        .map(String::as_str)
+        .filter(|arg| !arg.starts_with('-'))
+}
+
+/// Resolves launch arguments into absolute, deduplicated document paths.
+///
+/// Relative paths are canonicalized against `cwd` (the directory the
+/// launching process was started from), since a second instance's argv is
+/// otherwise meaningless once it's been handed off to the first instance.
+fn resolve_documents(argv: &[String], cwd: &Path) -> Vec<PathBuf> {
+    let mut seen = std::collections::HashSet::new();
+    let mut paths = Vec::new();
+
+    for arg in document_args(argv) {
+        let path = cwd.join(arg);
+        let path = path.canonicalize().unwrap_or(path);
+        if seen.insert(path.clone()) {
+            paths.push(path);
+        }
+    }
+
+    paths
+}
+
+/// Routes a launch or second-instance `argv`. On Linux and Windows, a
+/// `regia://` deep link arrives as a plain argv token once the scheme is
+/// registered (macOS is the exception — it delivers those as
+/// `RunEvent::Opened` instead), so tokens that parse as a `regia:` URL are
+/// scheme-sniffed and routed like any other deep link before anything left
+/// over is treated as a document path.
+fn activate_argv(app: &tauri::AppHandle, argv: &[String], cwd: &Path) {
+    let mut path_args = vec![argv.first().cloned().unwrap_or_default()];
+    let mut links = Vec::new();
+
+    for arg in document_args(argv) {
+        match url::Url::parse(arg) {
+            Ok(url) if url.scheme() == "regia" => links.push(url),
+            _ => path_args.push(arg.to_string()),
+        }
+    }
+
+    activate_urls(app, &links);
+    for path in resolve_documents(&path_args, cwd) {
+        PendingActivations::activate(app, ActivationTarget::Document(path));
+    }
+}
+
+/// Routes the URLs from a file-association or `regia://` deep-link
+/// activation (macOS `RunEvent::Opened`, or `regia:` tokens sniffed out of
+/// argv by `activate_argv`) through the same single activation path as
+/// argv-based opens.
+fn activate_urls(app: &tauri::AppHandle, urls: &[url::Url]) {
+    for url in urls {
+        let target = if url.scheme() == "file" {
+            match url.to_file_path() {
+                Ok(path) => ActivationTarget::Document(path),
+                Err(_) => ActivationTarget::Link(url.to_string()),
+            }
+        } else {
+            ActivationTarget::Link(url.to_string())
+        };
+        PendingActivations::activate(app, target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_args_skips_binary_name_and_flags() {
+        let argv = vec!["regia".to_string(), "--headless".to_string(), "report.pdf".to_string()];
+        let docs: Vec<&str> = document_args(&argv).collect();
+        assert_eq!(docs, vec!["report.pdf"]);
+    }
+
+    #[test]
+    fn resolve_documents_canonicalizes_relative_paths_against_cwd() {
+        let dir = std::env::temp_dir().join(format!("regia-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.pdf"), b"").unwrap();
+
+        let argv = vec!["regia".to_string(), "a.pdf".to_string()];
+        let resolved = resolve_documents(&argv, &dir);
+
+        assert_eq!(resolved, vec![dir.join("a.pdf").canonicalize().unwrap()]);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn resolve_documents_deduplicates_repeated_paths() {
+        let cwd = std::env::temp_dir();
+        let argv = vec!["regia".to_string(), "missing.pdf".to_string(), "missing.pdf".to_string()];
+        let resolved = resolve_documents(&argv, &cwd);
+        assert_eq!(resolved.len(), 1);
+    }
+
+    #[test]
+    fn resolve_documents_ignores_flags() {
+        let cwd = std::env::temp_dir();
+        let argv = vec!["regia".to_string(), "--flag".to_string(), "-x".to_string()];
+        assert!(resolve_documents(&argv, &cwd).is_empty());
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let mut builder = tauri::Builder::default();
+
+    // `tauri_plugin_single_instance` is desktop-only; mobile targets never
+    // have a "second instance" to redirect. Must be the first plugin
+    // registered on desktop: it needs to short-circuit a second launch
+    // before any other plugin's setup work runs.
+    #[cfg(desktop)]
+    {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            activate_argv(app, &argv, Path::new(&cwd));
+        }));
+    }
+
+    builder
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_http::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_deep_link::init())
+        .register_uri_scheme_protocol("regia-doc", protocol::handle)
+        .invoke_handler(tauri::generate_handler![
+            commands::open_document,
+            commands::extract_text,
+            commands::analyze_document,
+            commands::cancel_job,
+            commands::search_index,
+            commands::focus_document,
+        ])
+        .manage(PendingActivations::default())
+        .manage(DocumentStore::default())
+        .manage(JobRegistry::default())
         .setup(|app| {
             let window = app.get_webview_window("main").unwrap();
             window.set_title("Regia - Document Intelligence").ok();
+
+            // Associates the schemes declared in tauri.conf.json with this
+            // binary at runtime; required on Linux/Windows (and macOS dev
+            // builds) where the OS doesn't pick them up from bundle
+            // metadata alone.
+            #[cfg(any(target_os = "linux", windows, debug_assertions))]
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+                app.deep_link().register_all()?;
+            }
+
+            let app_handle = app.handle().clone();
+            window.once("tauri://page-load", move |_| {
+                PendingActivations::flush(&app_handle);
+            });
+
+            let argv: Vec<String> = std::env::args().collect();
+            let cwd = std::env::current_dir()?;
+            activate_argv(app.handle(), &argv, &cwd);
+
+            #[cfg(feature = "background-mode")]
+            {
+                tray::build(app.handle())?;
+
+                let app_handle = app.handle().clone();
+                window.on_window_event(move |event| {
+                    if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                        api.prevent_close();
+                        tray::hide_main(&app_handle);
+                    }
+                });
+            }
+
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running Regia");
+        .build(tauri::generate_context!())
+        .expect("error while building Regia")
+        .run(|app_handle, event| {
+            if let tauri::RunEvent::Opened { urls } = event {
+                activate_urls(app_handle, &urls);
+            }
+        });
 }
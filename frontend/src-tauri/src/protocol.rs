@@ -0,0 +1,141 @@
+use http::{Request, Response, StatusCode};
+use tauri::Manager;
+
+use crate::documents::{DocumentStore, RenderedPage};
+
+/// Serves rendered document pages over `regia-doc://<docid>/page/<n>`,
+/// honoring `Range` requests so the webview can progressively load and
+/// seek large pages without round-tripping base64 blobs through IPC.
+pub fn handle(app: &tauri::AppHandle, request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let Some((doc_id, page)) = parse_target(&request) else {
+        return not_found();
+    };
+
+    let store = app.state::<DocumentStore>();
+    let Some(page) = store.page(&doc_id, page) else {
+        return not_found();
+    };
+
+    match request
+        .headers()
+        .get(http::header::RANGE)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(range) => partial_response(&page, range),
+        None => full_response(&page),
+    }
+}
+
+/// Pulls the doc id (the authority) and page number (the `/page/<n>` path)
+/// out of a `regia-doc://` request.
+fn parse_target(request: &Request<Vec<u8>>) -> Option<(String, u32)> {
+    let url = url::Url::parse(&request.uri().to_string()).ok()?;
+    let doc_id = url.host_str()?.to_string();
+    let mut segments = url.path_segments()?;
+    if segments.next()? != "page" {
+        return None;
+    }
+    let page = segments.next()?.parse().ok()?;
+    Some((doc_id, page))
+}
+
+/// Parses a single `bytes=start-end` range header into an inclusive,
+/// bounds-checked `(start, end)` pair.
+fn parse_range(range: &str, total: usize) -> Option<(usize, usize)> {
+    let spec = range.strip_prefix("bytes=")?;
+    let (start, end) = spec.split_once('-')?;
+    let start: usize = start.parse().ok()?;
+    let end = if end.is_empty() {
+        total.saturating_sub(1)
+    } else {
+        end.parse().ok()?
+    };
+    if total == 0 || start > end || end >= total {
+        return None;
+    }
+    Some((start, end))
+}
+
+fn partial_response(page: &RenderedPage, range: &str) -> Response<Vec<u8>> {
+    let total = page.bytes.len();
+    let Some((start, end)) = parse_range(range, total) else {
+        return full_response(page);
+    };
+
+    Response::builder()
+        .status(StatusCode::PARTIAL_CONTENT)
+        .header(http::header::CONTENT_TYPE, &page.content_type)
+        .header(http::header::CONTENT_RANGE, format!("bytes {start}-{end}/{total}"))
+        .header(http::header::ACCEPT_RANGES, "bytes")
+        .body(page.bytes[start..=end].to_vec())
+        .unwrap()
+}
+
+fn full_response(page: &RenderedPage) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, &page.content_type)
+        .header(http::header::ACCEPT_RANGES, "bytes")
+        .body(page.bytes.to_vec())
+        .unwrap()
+}
+
+fn not_found() -> Response<Vec<u8>> {
+    Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body(Vec::new())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_target_reads_doc_id_and_page() {
+        let request = Request::builder()
+            .uri("regia-doc://abc123/page/4")
+            .body(Vec::new())
+            .unwrap();
+        assert_eq!(parse_target(&request), Some(("abc123".to_string(), 4)));
+    }
+
+    #[test]
+    fn parse_target_rejects_non_page_paths() {
+        let request = Request::builder()
+            .uri("regia-doc://abc123/thumbnail/4")
+            .body(Vec::new())
+            .unwrap();
+        assert_eq!(parse_target(&request), None);
+    }
+
+    #[test]
+    fn parse_range_open_ended_covers_to_end() {
+        assert_eq!(parse_range("bytes=10-", 100), Some((10, 99)));
+    }
+
+    #[test]
+    fn parse_range_bounded() {
+        assert_eq!(parse_range("bytes=0-9", 100), Some((0, 9)));
+    }
+
+    #[test]
+    fn parse_range_rejects_out_of_bounds() {
+        assert_eq!(parse_range("bytes=90-99", 50), None);
+    }
+
+    #[test]
+    fn parse_range_rejects_inverted_bounds() {
+        assert_eq!(parse_range("bytes=10-5", 100), None);
+    }
+
+    #[test]
+    fn parse_range_rejects_malformed_header() {
+        assert_eq!(parse_range("not-a-range", 100), None);
+    }
+
+    #[test]
+    fn parse_range_rejects_empty_body() {
+        assert_eq!(parse_range("bytes=0-0", 0), None);
+    }
+}
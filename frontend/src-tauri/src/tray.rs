@@ -0,0 +1,60 @@
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::{TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Manager};
+
+/// Builds the background-mode system tray (Open, Recent Documents, Quit).
+/// Only wired up when the `background-mode` feature is enabled.
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let open = MenuItem::with_id(app, "open", "Open", true, None::<&str>)?;
+    let recent = MenuItem::with_id(app, "recent", "Recent Documents", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&open, &recent, &quit])?;
+
+    let icon = app.default_window_icon().cloned().ok_or_else(|| {
+        tauri::Error::AssetNotFound("no default window icon configured for the tray".into())
+    })?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .icon(icon)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "open" => show_main(app),
+            // The recent-documents list isn't tracked yet; for now this
+            // just brings Regia back to the foreground like "Open" does.
+            "recent" => show_main(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click { .. } = event {
+                show_main(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Re-shows and focuses the main window, restoring the regular (Dock-
+/// visible) activation policy on macOS.
+pub fn show_main(app: &AppHandle) {
+    #[cfg(target_os = "macos")]
+    app.set_activation_policy(tauri::ActivationPolicy::Regular).ok();
+
+    if let Some(window) = app.get_webview_window("main") {
+        window.show().ok();
+        window.unminimize().ok();
+        window.set_focus().ok();
+    }
+}
+
+/// Hides the main window and, on macOS, drops Regia out of the Dock so it
+/// can keep its indexing/watch tasks alive without a visible window.
+pub fn hide_main(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        window.hide().ok();
+    }
+
+    #[cfg(target_os = "macos")]
+    app.set_activation_policy(tauri::ActivationPolicy::Accessory).ok();
+}